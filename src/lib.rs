@@ -1,3 +1,15 @@
+//! `#[derive(ValueObject)]` generates `Serialize`/`Deserialize`, `Display`, `TryFrom`,
+//! `FromStr`, inner-access (`Deref`/`AsRef`/`Borrow`/`into_inner`), and comparison
+//! (`PartialEq`/`Eq`/`PartialOrd`/`Ord`/`Hash`) impls that all forward to a single inner field.
+//! The constructing impls (`Deserialize`, `TryFrom`, `FromStr`) are routed through a
+//! user-supplied `load_fn` that enforces the type's invariant.
+//!
+//! Most of these are on **by default** (each has a `*_derive = false` escape hatch). That means
+//! `#[derive(ValueObject, PartialEq, Eq, Hash)]`, or a hand-written `impl From<Inner> for T`,
+//! conflicts with what this macro already generates (E0119, "conflicting implementations").
+//! Set the matching `eq_derive`/`hash_derive`/`ord_derive`/`as_ref_derive`/`into_inner_derive`/
+//! `deref_derive`/etc. to `false` on any struct that derives or hand-writes one of these itself.
+
 #[macro_use]
 extern crate syn;
 extern crate darling;
@@ -6,9 +6,96 @@ use proc_macro2::TokenStream as TokenStream2;
 use darling::Error;
 use quote::ToTokens;
 
+// A `#[value_object(inner_attrs(...))]` payload: an arbitrary, comma-separated list of nested
+// attributes (e.g. `inner_attrs(serde(rename_all = "camelCase"))`) captured verbatim so they
+// can be re-emitted elsewhere. There's no built-in darling type for "list of raw `syn::Meta`",
+// so this wraps one and implements `FromMeta` by hand, same spirit as darling's own `PathList`.
+#[derive(Debug, Default, Clone)]
+struct InnerAttrs(Vec<syn::Meta>);
+
+impl darling::FromMeta for InnerAttrs {
+    fn from_list(items: &[syn::NestedMeta]) -> Result<Self, Error> {
+        let mut metas = Vec::with_capacity(items.len());
+        for item in items {
+            match item {
+                syn::NestedMeta::Meta(meta) => {
+                    metas.push(meta.clone());
+                },
+                syn::NestedMeta::Lit(lit) => {
+                    return Err(Error::custom("expected a nested attribute, found a literal").with_span(lit));
+                },
+            }
+        }
+        return Ok(InnerAttrs(metas));
+    }
+}
+
+// The private wrapper type `wrap_inner_attrs` generates around a wire value, plus the bits of
+// codegen that differ between its two shapes: a transparent tuple struct (no wire-level key)
+// when `inner_attrs` only carries value-level attrs, or a named single-field struct (its field
+// called `value`) when `rename`/`rename_all` need an actual field name to act on.
+struct WrappedInnerAttrs {
+    ident: syn::Ident,
+    named: bool,
+    field_attrs: TokenStream2,
+    wrapped_type: TokenStream2,
+}
+
+impl WrappedInnerAttrs {
+    // `#wire_ident::deserialize(deserializer)?` plus however you reach the wrapped value back out.
+    fn deserialize_expr(&self) -> TokenStream2 {
+        let ident = &self.ident;
+        if self.named {
+            quote! { #ident::deserialize(deserializer)?.value }
+        } else {
+            quote! { #ident::deserialize(deserializer)?.0 }
+        }
+    }
+
+    // Builds a wire value from `value`, ready to `.serialize(serializer)`.
+    fn construct(&self, value: TokenStream2) -> TokenStream2 {
+        let ident = &self.ident;
+        if self.named {
+            quote! { #ident { value: #value } }
+        } else {
+            quote! { #ident(#value) }
+        }
+    }
+
+    // Defines the wire struct with only the `derive`s the caller's impl actually needs. Callers
+    // emit this once per impl (Deserialize, Serialize) rather than sharing one definition, so
+    // deriving only `Deserialize` here never drags a `Serialize` bound onto the serialize impl's
+    // generics, and vice versa -- the two copies are in separate fn bodies, so the shared struct
+    // name doesn't collide.
+    fn prelude(&self, derive: TokenStream2) -> TokenStream2 {
+        let ident = &self.ident;
+        let field_attrs = &self.field_attrs;
+        let wrapped_type = &self.wrapped_type;
+        if self.named {
+            quote! {
+                #[derive(#derive)]
+                struct #ident {
+                    #field_attrs
+                    value: #wrapped_type,
+                }
+            }
+        } else {
+            quote! {
+                #[derive(#derive)]
+                struct #ident(#field_attrs #wrapped_type);
+            }
+        }
+    }
+}
+
 #[derive(Debug, FromDeriveInput)]
-#[darling(attributes(value_object))]
+#[darling(attributes(value_object), forward_attrs(allow, cfg))]
 pub struct ValueObjectAttributes {
+    // Forwarded `#[allow(...)]`/`#[cfg(...)]` attributes already present on the struct, so the
+    // generated impls stay subject to the same lint suppressions and conditional compilation
+    // the struct itself was written with, instead of silently dropping them.
+    #[darling(default)]
+    attrs: Vec<syn::Attribute>,
     #[darling(rename = "error_type")]
     error_type: syn::Path,
     #[darling(rename = "load_fn")]
@@ -23,6 +110,49 @@ pub struct ValueObjectAttributes {
     try_from_derive: Option<bool>,
     #[darling(rename = "from_str_derive", default)]
     from_str_derive: Option<bool>,
+    // `deref_derive`/`as_ref_derive`/`into_inner_derive` below default on (see the comment on
+    // `deref_derive` in `generate_accessors` for why that's a deliberate, if breaking-change-prone, choice):
+    // a struct that also `#[derive(...)]`s or hand-writes one of `Deref`/`AsRef`/`Borrow`/
+    // `From<T> for Inner` conflicts with it (E0119) and must set the matching flag to `false`.
+    #[darling(rename = "deref_derive", default)]
+    deref_derive: Option<bool>,
+    #[darling(rename = "deref_mut_derive", default)]
+    deref_mut_derive: Option<bool>,
+    #[darling(rename = "as_ref_derive", default)]
+    as_ref_derive: Option<bool>,
+    #[darling(rename = "as_mut_derive", default)]
+    as_mut_derive: Option<bool>,
+    #[darling(rename = "into_inner_derive", default)]
+    into_inner_derive: Option<bool>,
+    #[darling(rename = "serde_as", default)]
+    serde_as: Option<syn::Path>,
+    // `Into`/`From` is the default proxy conversion (matches most wire shapes, which are
+    // infallible to build from the validated inner value); set this when the conversion back
+    // from the proxy can fail instead, e.g. the proxy represents a wider range of wire values
+    // than the inner type accepts.
+    #[darling(rename = "serde_as_try_from", default)]
+    serde_as_try_from: Option<bool>,
+    // Same default-on/E0119 hazard as above: a struct that also `#[derive(PartialEq, Eq,
+    // Hash)]`s or `#[derive(PartialOrd, Ord)]`s -- the single most common way to opt into
+    // comparisons in plain Rust -- conflicts with what these generate. Set `eq_derive`/
+    // `ord_derive`/`hash_derive` to `false` when hand-rolling or deriving these yourself.
+    #[darling(rename = "eq_derive", default)]
+    eq_derive: Option<bool>,
+    #[darling(rename = "ord_derive", default)]
+    ord_derive: Option<bool>,
+    #[darling(rename = "hash_derive", default)]
+    hash_derive: Option<bool>,
+    #[darling(rename = "parse_error_fn", default)]
+    parse_error_fn: Option<syn::Path>,
+    // Spliced onto the sole field of a private single-field wrapper around the wire value (the
+    // proxy type under `serde_as`, or the inner type in the plain transparent path). Value-level
+    // attrs like `#[serde(deserialize_with = "...")]` are spliced onto a *tuple* wrapper, which
+    // (de)serializes transparently, so they take effect without adding a wire-level nesting key.
+    // `rename`/`rename_all` need an actual field name to act on, so their presence switches the
+    // wrapper to a single *named* field (called `value`, subject to the rename) instead -- see
+    // `wrap_inner_attrs`.
+    #[darling(rename = "inner_attrs", default)]
+    inner_attrs: InnerAttrs,
 }
 
 #[derive(Debug)]
@@ -44,14 +174,81 @@ impl ValueObject {
     }
 
     pub fn validate(&self) -> Result<(), Error> {
-        if !self.generics.params.is_empty() {
-            return Err(Error::custom(format!("Generics not allowed in value-object `{}`", self.ident)));
+        let comparisons_default = self.comparisons_default_enabled()?;
+        if self.attributes.ord_derive.unwrap_or(comparisons_default) && !self.attributes.eq_derive.unwrap_or(comparisons_default) {
+            return Err(Error::custom("`ord_derive` requires `eq_derive`, since `Ord` requires `Eq`"));
         }
-        let _ = self.get_internal_type()?;
         return Ok(());
     }
 
-    fn get_internal_type(&self) -> Result<syn::Type, Error> {
+    // `rename`/`rename_all` only mean something on a *named* field (or a multi-field
+    // container, for `rename_all`), so their presence in `inner_attrs` is what decides whether
+    // `wrap_inner_attrs` emits a named single-field wrapper (giving them a `value` field to act
+    // on) or a transparent tuple wrapper (no wire-level nesting key at all). Walk the nested
+    // metas too, since `serde(rename_all = "camelCase")` is itself one level of nesting.
+    const NAME_ONLY_INNER_ATTRS: [&'static str; 2] = ["rename", "rename_all"];
+
+    fn meta_needs_named_field(meta: &syn::Meta) -> bool {
+        if let Some(ident) = meta.path().get_ident() {
+            if Self::NAME_ONLY_INNER_ATTRS.contains(&ident.to_string().as_str()) {
+                return true;
+            }
+        }
+        if let syn::Meta::List(list) = meta {
+            return list.nested.iter().any(|nested| match nested {
+                syn::NestedMeta::Meta(nested_meta) => Self::meta_needs_named_field(nested_meta),
+                syn::NestedMeta::Lit(_) => false,
+            });
+        }
+        return false;
+    }
+
+    fn inner_attrs_need_named_field(&self) -> bool {
+        return self.attributes.inner_attrs.0.iter().any(Self::meta_needs_named_field);
+    }
+
+    // Appends `bounds` to `generics`'s where-clause in place.
+    fn push_where_bounds(generics: &mut syn::Generics, bounds: Vec<TokenStream2>) {
+        if bounds.is_empty() {
+            return;
+        }
+        let where_clause = generics.make_where_clause();
+        for bound in bounds {
+            where_clause.predicates.push(syn::parse_quote!(#bound));
+        }
+    }
+
+    // Clones `self.generics` and appends `bounds` to its where-clause, leaving the impl's own
+    // type/lifetime parameter list untouched. Used to require e.g. `T: Display` only on the
+    // impls that actually need it, without forcing that bound onto every generated impl.
+    fn generics_with_bounds(&self, bounds: Vec<TokenStream2>) -> syn::Generics {
+        let mut generics = self.generics.clone();
+        Self::push_where_bounds(&mut generics, bounds);
+        return generics;
+    }
+
+    // A `'de` lifetime for the generated `Deserialize` impl that can't collide with a lifetime
+    // already declared on the struct, following serde_derive's approach of picking a fresh name
+    // instead of hardcoding `impl<'de>`.
+    fn fresh_de_lifetime(&self) -> syn::Lifetime {
+        let mut name = String::from("de");
+        while self.generics.lifetimes().any(|lifetime_def| lifetime_def.lifetime.ident == name) {
+            name.push('_');
+        }
+        return syn::Lifetime::new(&format!("'{}", name), proc_macro2::Span::call_site());
+    }
+
+    // Clones `self.generics` with the fresh `'de` lifetime inserted as the impl's first generic
+    // parameter, for use in a `Deserialize<'de>` impl. Bounds referencing `'de` must be pushed
+    // by the caller once it has this lifetime in hand.
+    fn generics_with_de_lifetime(&self) -> (syn::Generics, syn::Lifetime) {
+        let de_lifetime = self.fresh_de_lifetime();
+        let mut generics = self.generics.clone();
+        generics.params.insert(0, syn::GenericParam::Lifetime(syn::LifetimeDef::new(de_lifetime.clone())));
+        return (generics, de_lifetime);
+    }
+
+    fn get_internal_type(&self) -> Result<(syn::Type, TokenStream2), Error> {
         let struct_internals = match &self.struct_internals {
             &syn::Data::Struct(ref struct_internals) => {
                 struct_internals
@@ -63,24 +260,40 @@ impl ValueObject {
                 return Err(Error::custom("Union struct not supported"));
             },
         };
-        let field = match struct_internals.fields {
+        let (field, accessor) = match struct_internals.fields {
             syn::Fields::Named(ref fields) => {
                 if fields.named.len() != 1 {
-                    return Err(Error::custom("Object value must contain only one field"));    
+                    return Err(Error::custom("Object value must contain only one field"));
                 }
-                fields.named[0].clone()
+                let field = fields.named[0].clone();
+                let accessor = field.ident.clone().expect("named field must have an ident").into_token_stream();
+                (field, accessor)
             },
             syn::Fields::Unnamed(ref fields) => {
                 if fields.unnamed.len() != 1 {
-                    return Err(Error::custom("Object value must contain only one field"));    
+                    return Err(Error::custom("Object value must contain only one field"));
                 }
-                fields.unnamed[0].clone()
+                let field = fields.unnamed[0].clone();
+                let accessor = syn::Index::from(0).into_token_stream();
+                (field, accessor)
             },
             syn::Fields::Unit => {
                 return Err(Error::custom("Empty structs does not supported"));
             },
         };
-        return Ok(field.ty);
+        return Ok((field.ty, accessor));
+    }
+
+    // Types for which `eq_derive`/`ord_derive`/`hash_derive` cannot sensibly default to `true`,
+    // since they don't implement the corresponding `std` trait at all (`f32`/`f64` have no
+    // total `Eq`/`Ord`/`Hash`). Mirrors the `is_default_type` gating `from_str_derive` already
+    // does for `FROM_STR_DEFAULT_TYPES`, but in the opposite direction: default on, except here.
+    const NON_TOTAL_ORD_TYPES: [&'static str; 2] = ["f32", "f64"];
+
+    fn comparisons_default_enabled(&self) -> Result<bool, Error> {
+        let (internal_type, _) = self.get_internal_type()?;
+        let internal_type_str = format!("{}", internal_type.to_token_stream());
+        return Ok(!Self::NON_TOTAL_ORD_TYPES.contains(&internal_type_str.as_str()));
     }
 
     fn generate_serde(&self) -> Result<TokenStream2, Error> {
@@ -97,23 +310,191 @@ impl ValueObject {
         let serde_crate = syn::Ident::new(serde_crate, proc_macro2::Span::call_site());
         let ident = &self.ident;
         let load_fn = &self.attributes.load_fn;
-        let internal_type = self.get_internal_type()?;
+        let (internal_type, field_accessor) = self.get_internal_type()?;
+        if let Some(proxy_type) = &self.attributes.serde_as {
+            return self.generate_serde_as(&serde_crate, proxy_type, &internal_type, &field_accessor);
+        }
+        let (_, ty_generics, _) = self.generics.split_for_impl();
+        let (mut de_generics, de_lifetime) = self.generics_with_de_lifetime();
+        Self::push_where_bounds(&mut de_generics, vec![
+            quote! { #internal_type: #serde_crate::de::Deserialize<#de_lifetime> },
+        ]);
+        let (de_impl_generics, _, de_where_clause) = de_generics.split_for_impl();
+
+        let wrapped = self.wrap_inner_attrs("", &internal_type);
+        let (wire_prelude, deserialize_value) = match &wrapped {
+            Some(wrapped) => (
+                wrapped.prelude(quote! { #serde_crate::Deserialize }),
+                wrapped.deserialize_expr(),
+            ),
+            None => (
+                TokenStream2::new(),
+                quote! { <#internal_type as #serde_crate::de::Deserialize<#de_lifetime>>::deserialize(deserializer)? },
+            ),
+        };
+
+        let mut ser_bounds = vec![quote! { #internal_type: #serde_crate::Serialize }];
+        if wrapped.is_some() {
+            ser_bounds.push(quote! { #internal_type: std::clone::Clone });
+        }
+        let ser_generics = self.generics_with_bounds(ser_bounds);
+        let (ser_impl_generics, _, ser_where_clause) = ser_generics.split_for_impl();
+        let serialize_body = match &wrapped {
+            Some(wrapped) => {
+                let prelude = wrapped.prelude(quote! { #serde_crate::Serialize });
+                let wire_value = wrapped.construct(quote! { self.#field_accessor.clone() });
+                quote! {
+                    #prelude
+                    #wire_value.serialize(serializer)
+                }
+            },
+            None => quote! {
+                self.#field_accessor.serialize(serializer)
+            },
+        };
+
         return Ok(quote! {
-            impl <'de>#serde_crate::de::Deserialize<'de> for #ident {
-                fn deserialize<D>(deserializer: D) -> Result<#ident, D::Error> where
-                    D: #serde_crate::de::Deserializer<'de> {
-                    let value = #internal_type::deserialize(deserializer)?;
+            impl #de_impl_generics #serde_crate::de::Deserialize<#de_lifetime> for #ident #ty_generics #de_where_clause {
+                fn deserialize<D>(deserializer: D) -> Result<#ident #ty_generics, D::Error> where
+                    D: #serde_crate::de::Deserializer<#de_lifetime> {
+                    #wire_prelude
+                    let value = #deserialize_value;
                     let value = #load_fn(value).map_err(#serde_crate::de::Error::custom)?;
                     return Ok(value);
                 }
             }
-            
-            impl #serde_crate::Serialize for #ident {
+
+            impl #ser_impl_generics #serde_crate::Serialize for #ident #ty_generics #ser_where_clause {
                 fn serialize<S>(&self, serializer: S) -> Result<S::Ok, S::Error> where
                     S: #serde_crate::Serializer {
-                    self.0.serialize(serializer)
+                    #serialize_body
                 }
-            }            
+            }
+        });
+    }
+
+    // Shared by the transparent path above and `generate_serde_as` below: when `inner_attrs`
+    // is set, wraps `wrapped_type` in a private single-field struct carrying those attrs on its
+    // one field. The struct itself isn't emitted here -- callers define their own copy (via
+    // `WrappedInnerAttrs::prelude`) in each impl that needs it, deriving only `Serialize` or
+    // only `Deserialize` as that impl requires, instead of deriving both unconditionally (which
+    // would force both bounds onto every impl, including the ones that only need one). Picks
+    // the wrapper's shape based on `inner_attrs_need_named_field`:
+    //   - no `rename`/`rename_all`: a tuple struct, which (de)serializes transparently (no
+    //     wire-level nesting key), so attrs like `with`/`deserialize_with`/`serialize_with`/
+    //     `bound` take effect without reshaping the wire format -- the same way serde_with's
+    //     `apply` rule works on an individual field.
+    //   - `rename`/`rename_all` present: a named struct (field `value`), since renaming needs
+    //     an actual field name to act on; this does add one nesting level, as an explicit
+    //     consequence of asking for a rename, not an implicit default.
+    // Returns `None` when there are no `inner_attrs` to honor, so callers can fall back to
+    // their existing, wrapper-free codegen.
+    fn wrap_inner_attrs(
+        &self,
+        label: &str,
+        wrapped_type: &impl ToTokens,
+    ) -> Option<WrappedInnerAttrs> {
+        let inner_attrs = &self.attributes.inner_attrs.0;
+        if inner_attrs.is_empty() {
+            return None;
+        }
+        let ident = &self.ident;
+        let wire_ident = syn::Ident::new(&format!("__{}{}Wire", ident, label), proc_macro2::Span::call_site());
+        let named = self.inner_attrs_need_named_field();
+        let field_attrs = quote! { #(#[#inner_attrs])* };
+        let wrapped_type = quote! { #wrapped_type };
+        return Some(WrappedInnerAttrs { ident: wire_ident, named, field_attrs, wrapped_type });
+    }
+
+    // `serde_as`: the wire form is a distinct proxy type that gets converted into the inner
+    // type (and back) around the existing `load_fn` validation, mirroring serde_with's
+    // `serde_as` proxy types instead of (de)serializing through the inner type directly.
+    //
+    // `inner_attrs`, when set, are honored via `wrap_inner_attrs` around `proxy_type` itself --
+    // see that doc comment for the two wrapper shapes it can produce.
+    fn generate_serde_as(
+        &self,
+        serde_crate: &syn::Ident,
+        proxy_type: &syn::Path,
+        internal_type: &syn::Type,
+        field_accessor: &TokenStream2,
+    ) -> Result<TokenStream2, Error> {
+        let ident = &self.ident;
+        let load_fn = &self.attributes.load_fn;
+        let try_from_enabled = self.attributes.serde_as_try_from.unwrap_or(false);
+        let (_, ty_generics, _) = self.generics.split_for_impl();
+
+        let wrapped = self.wrap_inner_attrs("Proxy", proxy_type);
+        let (de_wire_prelude, deserialize_proxy) = match &wrapped {
+            Some(wrapped) => (wrapped.prelude(quote! { #serde_crate::Deserialize }), wrapped.deserialize_expr()),
+            None => (TokenStream2::new(), quote! { #proxy_type::deserialize(deserializer)? }),
+        };
+        let (ser_wire_prelude, wrap_for_serialize) = match &wrapped {
+            Some(wrapped) => (
+                wrapped.prelude(quote! { #serde_crate::Serialize }),
+                wrapped.construct(quote! { #proxy_type::from(self.#field_accessor.clone()) }),
+            ),
+            None => (TokenStream2::new(), quote! { #proxy_type::from(self.#field_accessor.clone()) }),
+        };
+
+        let (mut de_generics, de_lifetime) = self.generics_with_de_lifetime();
+        let conversion_bound = if try_from_enabled {
+            quote! { #internal_type: std::convert::TryFrom<#proxy_type> }
+        } else {
+            quote! { #internal_type: std::convert::From<#proxy_type> }
+        };
+        Self::push_where_bounds(&mut de_generics, vec![
+            quote! { #proxy_type: #serde_crate::de::Deserialize<#de_lifetime> },
+            conversion_bound,
+        ]);
+        let (de_impl_generics, _, de_where_clause) = de_generics.split_for_impl();
+
+        let deserialize_impl = if try_from_enabled {
+            quote! {
+                impl #de_impl_generics #serde_crate::de::Deserialize<#de_lifetime> for #ident #ty_generics #de_where_clause {
+                    fn deserialize<D>(deserializer: D) -> Result<#ident #ty_generics, D::Error> where
+                        D: #serde_crate::de::Deserializer<#de_lifetime> {
+                        #de_wire_prelude
+                        let proxy = #deserialize_proxy;
+                        let value = <#internal_type as std::convert::TryFrom<#proxy_type>>::try_from(proxy)
+                            .map_err(#serde_crate::de::Error::custom)?;
+                        let value = #load_fn(value).map_err(#serde_crate::de::Error::custom)?;
+                        return Ok(value);
+                    }
+                }
+            }
+        } else {
+            quote! {
+                impl #de_impl_generics #serde_crate::de::Deserialize<#de_lifetime> for #ident #ty_generics #de_where_clause {
+                    fn deserialize<D>(deserializer: D) -> Result<#ident #ty_generics, D::Error> where
+                        D: #serde_crate::de::Deserializer<#de_lifetime> {
+                        #de_wire_prelude
+                        let proxy = #deserialize_proxy;
+                        let value = <#internal_type as std::convert::From<#proxy_type>>::from(proxy);
+                        let value = #load_fn(value).map_err(#serde_crate::de::Error::custom)?;
+                        return Ok(value);
+                    }
+                }
+            }
+        };
+
+        let ser_generics = self.generics_with_bounds(vec![
+            quote! { #internal_type: std::clone::Clone },
+            quote! { #proxy_type: std::convert::From<#internal_type> + #serde_crate::Serialize },
+        ]);
+        let (ser_impl_generics, _, ser_where_clause) = ser_generics.split_for_impl();
+
+        return Ok(quote! {
+            #deserialize_impl
+
+            impl #ser_impl_generics #serde_crate::Serialize for #ident #ty_generics #ser_where_clause {
+                fn serialize<S>(&self, serializer: S) -> Result<S::Ok, S::Error> where
+                    S: #serde_crate::Serializer {
+                    #ser_wire_prelude
+                    let proxy = #wrap_for_serialize;
+                    proxy.serialize(serializer)
+                }
+            }
         });
     }
 
@@ -126,10 +507,14 @@ impl ValueObject {
             return Ok(TokenStream2::new());
         }
         let ident = &self.ident;
+        let (internal_type, field_accessor) = self.get_internal_type()?;
+        let (_, ty_generics, _) = self.generics.split_for_impl();
+        let augmented = self.generics_with_bounds(vec![quote! { #internal_type: std::fmt::Display }]);
+        let (impl_generics, _, where_clause) = augmented.split_for_impl();
         return Ok(quote! {
-            impl std::fmt::Display for #ident {
+            impl #impl_generics std::fmt::Display for #ident #ty_generics #where_clause {
                 fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> Result<(), std::fmt::Error> {
-                    write!(f, "{}", self.0)
+                    write!(f, "{}", self.#field_accessor)
                 }
             }
         });
@@ -143,12 +528,13 @@ impl ValueObject {
         if !try_from_derive_enabled {
             return Ok(TokenStream2::new());
         }
-        let internal_type = self.get_internal_type()?;
+        let (internal_type, _) = self.get_internal_type()?;
         let error_type = &self.attributes.error_type;
         let ident = &self.ident;
         let load_fn = &self.attributes.load_fn;
+        let (impl_generics, ty_generics, where_clause) = self.generics.split_for_impl();
         return Ok(quote! {
-            impl std::convert::TryFrom<#internal_type> for #ident {
+            impl #impl_generics std::convert::TryFrom<#internal_type> for #ident #ty_generics #where_clause {
                 type Error = #error_type;
                 fn try_from(value: #internal_type) -> Result<Self, Self::Error> {
                     let value = #load_fn(value)?;
@@ -160,13 +546,13 @@ impl ValueObject {
 
     fn generate_from_str(&self) -> Result<TokenStream2, Error> {
         const FROM_STR_DEFAULT_TYPES: [&'static str; 17] = [
-            "bool", "char", 
-            "f32", "f64", 
+            "bool", "char",
+            "f32", "f64",
             "i8", "i16", "i32", "i64", "i128", "isize",
             "u8", "u16", "u32", "u64", "u128", "usize",
             "String",
         ];
-        let internal_type = self.get_internal_type()?;
+        let (internal_type, _) = self.get_internal_type()?;
         let internal_type_str = format!("{}", internal_type.to_token_stream());
         let internal_type_str = internal_type_str.as_str();
         let is_default_type = FROM_STR_DEFAULT_TYPES.contains(&internal_type_str);
@@ -180,11 +566,36 @@ impl ValueObject {
         let ident = &self.ident;
         let load_fn = &self.attributes.load_fn;
         let error_type = &self.attributes.error_type;
+        let (_, ty_generics, _) = self.generics.split_for_impl();
+        let mut bounds = vec![quote! { #internal_type: std::str::FromStr }];
+        // The default-on types above all have a `FromStr::Err` with no `From` impl into an
+        // arbitrary user `error_type` (e.g. `String`'s is `Infallible`, which nothing but `T:
+        // From<T>` converts into), so without an explicit `parse_error_fn` the bare `?` below
+        // never compiled for them. Stringify the parse error instead and require `error_type:
+        // From<String>` -- trivially satisfied when `error_type` is itself `String` (the
+        // reflexive `impl<T> From<T> for T`), and a one-line bound to add otherwise.
+        let parse_value = match &self.attributes.parse_error_fn {
+            Some(parse_error_fn) => quote! {
+                let value = <#internal_type as std::str::FromStr>::from_str(s).map_err(#parse_error_fn)?;
+            },
+            None if is_default_type => {
+                bounds.push(quote! { #error_type: std::convert::From<std::string::String> });
+                quote! {
+                    let value = <#internal_type as std::str::FromStr>::from_str(s)
+                        .map_err(|error| #error_type::from(error.to_string()))?;
+                }
+            },
+            None => quote! {
+                let value = <#internal_type as std::str::FromStr>::from_str(s)?;
+            },
+        };
+        let augmented = self.generics_with_bounds(bounds);
+        let (impl_generics, _, where_clause) = augmented.split_for_impl();
         return Ok(quote! {
-            impl std::str::FromStr for #ident {
+            impl #impl_generics std::str::FromStr for #ident #ty_generics #where_clause {
                 type Err = #error_type;
                 fn from_str(s: &str) -> Result<Self, Self::Err> {
-                    let value = #internal_type::from_str(s)?;
+                    #parse_value
                     let value = #load_fn(value)?;
                     return Ok(value);
                 }
@@ -192,17 +603,166 @@ impl ValueObject {
         });
     }
 
+    fn generate_accessors(&self) -> Result<TokenStream2, Error> {
+        let ident = &self.ident;
+        let (internal_type, field_accessor) = self.get_internal_type()?;
+        let (impl_generics, ty_generics, where_clause) = self.generics.split_for_impl();
+        let mut tokens = TokenStream2::new();
+
+        // Default-on, not opt-in like `derive_more`'s `Deref`: a value object here is meant to
+        // be a thin, validated wrapper callers reach through routinely, so transparent access
+        // to the inner value is the common case. Like the other accessors below, this can still
+        // collide with a hand-written or derived impl of the same trait downstream (E0119);
+        // set `deref_derive = false` to opt back out for a given struct.
+        if self.attributes.deref_derive.unwrap_or(true) {
+            tokens.extend(quote! {
+                impl #impl_generics std::ops::Deref for #ident #ty_generics #where_clause {
+                    type Target = #internal_type;
+                    fn deref(&self) -> &Self::Target {
+                        &self.#field_accessor
+                    }
+                }
+            });
+        }
+        // Opt-in: a hand-written `load_fn` may enforce an invariant on the inner value,
+        // so mutable access is not exposed by default, only when the caller accepts
+        // responsibility for keeping the inner value valid.
+        if self.attributes.deref_mut_derive.unwrap_or(false) {
+            tokens.extend(quote! {
+                impl #impl_generics std::ops::DerefMut for #ident #ty_generics #where_clause {
+                    fn deref_mut(&mut self) -> &mut Self::Target {
+                        &mut self.#field_accessor
+                    }
+                }
+            });
+        }
+        if self.attributes.as_ref_derive.unwrap_or(true) {
+            tokens.extend(quote! {
+                impl #impl_generics std::convert::AsRef<#internal_type> for #ident #ty_generics #where_clause {
+                    fn as_ref(&self) -> &#internal_type {
+                        &self.#field_accessor
+                    }
+                }
+                impl #impl_generics std::borrow::Borrow<#internal_type> for #ident #ty_generics #where_clause {
+                    fn borrow(&self) -> &#internal_type {
+                        &self.#field_accessor
+                    }
+                }
+            });
+        }
+        // Same rationale as `deref_mut_derive`: not generated unless explicitly requested.
+        if self.attributes.as_mut_derive.unwrap_or(false) {
+            tokens.extend(quote! {
+                impl #impl_generics std::convert::AsMut<#internal_type> for #ident #ty_generics #where_clause {
+                    fn as_mut(&mut self) -> &mut #internal_type {
+                        &mut self.#field_accessor
+                    }
+                }
+            });
+        }
+        if self.attributes.into_inner_derive.unwrap_or(true) {
+            tokens.extend(quote! {
+                impl #impl_generics #ident #ty_generics #where_clause {
+                    pub fn into_inner(self) -> #internal_type {
+                        self.#field_accessor
+                    }
+                }
+                impl #impl_generics std::convert::From<#ident #ty_generics> for #internal_type #where_clause {
+                    fn from(value: #ident #ty_generics) -> #internal_type {
+                        value.#field_accessor
+                    }
+                }
+            });
+        }
+        return Ok(tokens);
+    }
+
+    // `eq_derive`/`ord_derive`/`hash_derive`: forward comparison and hashing to the inner
+    // field rather than `#[derive(...)]`-ing them on the struct itself, so a `load_fn` that
+    // normalizes the inner value (e.g. case-folding) still gets objects that compare and hash
+    // identically whenever their normalized inner values match.
+    fn generate_comparisons(&self) -> Result<TokenStream2, Error> {
+        let ident = &self.ident;
+        let (internal_type, field_accessor) = self.get_internal_type()?;
+        let (_, ty_generics, _) = self.generics.split_for_impl();
+        let comparisons_default = self.comparisons_default_enabled()?;
+        let mut tokens = TokenStream2::new();
+
+        if self.attributes.eq_derive.unwrap_or(comparisons_default) {
+            let augmented = self.generics_with_bounds(vec![quote! { #internal_type: std::cmp::Eq }]);
+            let (impl_generics, _, where_clause) = augmented.split_for_impl();
+            tokens.extend(quote! {
+                impl #impl_generics std::cmp::PartialEq for #ident #ty_generics #where_clause {
+                    fn eq(&self, other: &Self) -> bool {
+                        std::cmp::PartialEq::eq(&self.#field_accessor, &other.#field_accessor)
+                    }
+                }
+                impl #impl_generics std::cmp::Eq for #ident #ty_generics #where_clause {}
+            });
+        }
+        if self.attributes.ord_derive.unwrap_or(comparisons_default) {
+            let augmented = self.generics_with_bounds(vec![quote! { #internal_type: std::cmp::Ord }]);
+            let (impl_generics, _, where_clause) = augmented.split_for_impl();
+            tokens.extend(quote! {
+                impl #impl_generics std::cmp::PartialOrd for #ident #ty_generics #where_clause {
+                    fn partial_cmp(&self, other: &Self) -> Option<std::cmp::Ordering> {
+                        std::cmp::PartialOrd::partial_cmp(&self.#field_accessor, &other.#field_accessor)
+                    }
+                }
+                impl #impl_generics std::cmp::Ord for #ident #ty_generics #where_clause {
+                    fn cmp(&self, other: &Self) -> std::cmp::Ordering {
+                        std::cmp::Ord::cmp(&self.#field_accessor, &other.#field_accessor)
+                    }
+                }
+            });
+        }
+        if self.attributes.hash_derive.unwrap_or(comparisons_default) {
+            let augmented = self.generics_with_bounds(vec![quote! { #internal_type: std::hash::Hash }]);
+            let (impl_generics, _, where_clause) = augmented.split_for_impl();
+            tokens.extend(quote! {
+                impl #impl_generics std::hash::Hash for #ident #ty_generics #where_clause {
+                    fn hash<H: std::hash::Hasher>(&self, state: &mut H) {
+                        std::hash::Hash::hash(&self.#field_accessor, state)
+                    }
+                }
+            });
+        }
+        return Ok(tokens);
+    }
+
     pub fn generate(&self) -> Result<TokenStream2, Error> {
         let serde_token = self.generate_serde()?;
         let display_token = self.generate_display()?;
         let try_from_token = self.generate_try_from()?;
         let from_str_token = self.generate_from_str()?;
-        let result = quote! {
+        let accessors_token = self.generate_accessors()?;
+        let comparisons_token = self.generate_comparisons()?;
+        let tokens = quote! {
             #serde_token
             #display_token
             #try_from_token
             #from_str_token
+            #accessors_token
+            #comparisons_token
         };
-        return Ok(result);
+        return self.forward_attrs_onto_impls(tokens);
+    }
+
+    // Re-attaches the `#[allow(...)]`/`#[cfg(...)]` attributes captured via `forward_attrs`
+    // onto every generated impl item, so e.g. `#[cfg(feature = "...")]` or
+    // `#[allow(clippy::...)]` already written on the struct also gates/quiets the code we
+    // generate for it.
+    fn forward_attrs_onto_impls(&self, tokens: TokenStream2) -> Result<TokenStream2, Error> {
+        if self.attributes.attrs.is_empty() {
+            return Ok(tokens);
+        }
+        let mut file: syn::File = syn::parse2(tokens)?;
+        for item in file.items.iter_mut() {
+            if let syn::Item::Impl(item_impl) = item {
+                item_impl.attrs.extend(self.attributes.attrs.iter().cloned());
+            }
+        }
+        let items = file.items;
+        return Ok(quote! { #(#items)* });
     }
 }
\ No newline at end of file
@@ -0,0 +1,44 @@
+#[macro_use]
+extern crate derive_value_object;
+
+use std::borrow::Borrow;
+use std::convert::AsRef;
+
+#[test]
+fn test_accessors_expose_read_only_access_to_the_inner_value() {
+    #[derive(Debug, ValueObject)]
+    #[value_object(load_fn="Username::new", error_type="String", from_str_derive=false)]
+    pub struct Username(String);
+
+    impl Username {
+        fn new(value: String) -> Result<Username, String> {
+            return Ok(Username(value));
+        }
+    }
+
+    let username = Username::new("ada".to_string()).unwrap();
+    assert_eq!(&*username, "ada");
+    assert_eq!(AsRef::<String>::as_ref(&username), "ada");
+    assert_eq!(Borrow::<String>::borrow(&username), "ada");
+    assert_eq!(String::from(Username::new("ada".to_string()).unwrap()), "ada");
+    assert_eq!(username.into_inner(), "ada");
+}
+
+#[test]
+fn test_deref_mut_is_opt_in() {
+    // `DerefMut`/`AsMut` must stay opt-in, since a hand-written `load_fn` may enforce an
+    // invariant a caller could otherwise bypass through mutable access to the inner value.
+    #[derive(Debug, ValueObject)]
+    #[value_object(load_fn="Username::new", error_type="String", from_str_derive=false, deref_mut_derive=true)]
+    pub struct Username(String);
+
+    impl Username {
+        fn new(value: String) -> Result<Username, String> {
+            return Ok(Username(value));
+        }
+    }
+
+    let mut username = Username::new("ada".to_string()).unwrap();
+    username.push_str("_lovelace");
+    assert_eq!(&*username, "ada_lovelace");
+}
@@ -0,0 +1,44 @@
+#[macro_use]
+extern crate derive_value_object;
+
+use std::collections::HashSet;
+
+#[test]
+fn test_comparisons_default_on_for_ord_types() {
+    #[derive(Debug, ValueObject)]
+    #[value_object(load_fn="Count::new", error_type="String", from_str_derive=false)]
+    pub struct Count(i64);
+
+    impl Count {
+        fn new(value: i64) -> Result<Count, String> {
+            return Ok(Count(value));
+        }
+    }
+
+    let a = Count::new(1).unwrap();
+    let b = Count::new(1).unwrap();
+    let c = Count::new(2).unwrap();
+    assert_eq!(a, b);
+    assert!(a < c);
+    let mut set = HashSet::new();
+    set.insert(a);
+    assert!(set.contains(&b));
+}
+
+#[test]
+fn test_comparisons_default_off_for_non_ord_inner_type() {
+    // `f64` implements neither `Eq`, `Ord` nor `Hash`, so these must not default to `true`
+    // or the generated impls fail to compile.
+    #[derive(Debug, ValueObject)]
+    #[value_object(load_fn="Price::new", error_type="String", from_str_derive=false)]
+    pub struct Price(f64);
+
+    impl Price {
+        fn new(value: f64) -> Result<Price, String> {
+            return Ok(Price(value));
+        }
+    }
+
+    let price = Price::new(4.2).unwrap();
+    assert_eq!(price.into_inner(), 4.2);
+}
@@ -0,0 +1,47 @@
+#[macro_use]
+extern crate derive_value_object;
+
+#[test]
+fn test_generic_value_object_roundtrips_through_serde() {
+    #[derive(Debug, ValueObject)]
+    #[value_object(load_fn="NonEmpty::new", error_type="String", from_str_derive=false)]
+    pub struct NonEmpty<T>(Vec<T>) where T: Clone;
+
+    impl<T: Clone> NonEmpty<T> {
+        fn new(value: Vec<T>) -> Result<NonEmpty<T>, String> {
+            if value.is_empty() {
+                return Err("must not be empty".to_string());
+            }
+            return Ok(NonEmpty(value));
+        }
+    }
+
+    let value: NonEmpty<i32> = NonEmpty::new(vec![1, 2, 3]).unwrap();
+    let json = serde_json::to_string(&value).unwrap();
+    assert_eq!(json, "[1,2,3]");
+    let parsed: NonEmpty<i32> = serde_json::from_str(&json).unwrap();
+    assert_eq!(parsed.into_inner(), vec![1, 2, 3]);
+
+    let result: Result<NonEmpty<i32>, _> = serde_json::from_str("[]");
+    assert!(result.is_err());
+}
+
+#[test]
+fn test_value_object_with_a_borrowed_lifetime() {
+    #[derive(Debug, ValueObject)]
+    #[value_object(load_fn="Name::new", error_type="String", from_str_derive=false, serde_derive=false)]
+    pub struct Name<'a>(&'a str);
+
+    impl<'a> Name<'a> {
+        fn new(value: &'a str) -> Result<Name<'a>, String> {
+            if value.is_empty() {
+                return Err("must not be empty".to_string());
+            }
+            return Ok(Name(value));
+        }
+    }
+
+    let name = Name::new("ada").unwrap();
+    assert_eq!(*name, "ada");
+    assert_eq!(format!("{}", name), "ada");
+}
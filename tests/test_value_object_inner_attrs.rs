@@ -0,0 +1,93 @@
+#[macro_use]
+extern crate derive_value_object;
+
+use serde::{Serialize, Deserialize, Deserializer};
+
+#[derive(Debug, Clone, Serialize, Deserialize)]
+struct CoordinatesProxy {
+    lat: f64,
+    lon: f64,
+}
+
+impl From<(f64, f64)> for CoordinatesProxy {
+    fn from(value: (f64, f64)) -> CoordinatesProxy {
+        return CoordinatesProxy { lat: value.0, lon: value.1 };
+    }
+}
+
+impl From<CoordinatesProxy> for (f64, f64) {
+    fn from(value: CoordinatesProxy) -> (f64, f64) {
+        return (value.lat, value.lon);
+    }
+}
+
+fn clamp_latitude<'de, D>(deserializer: D) -> Result<CoordinatesProxy, D::Error>
+where
+    D: Deserializer<'de> {
+    let mut proxy = CoordinatesProxy::deserialize(deserializer)?;
+    proxy.lat = proxy.lat.clamp(-90.0, 90.0);
+    return Ok(proxy);
+}
+
+#[test]
+fn test_inner_attrs_customizes_deserialization_without_nesting_the_wire_value() {
+    #[derive(Debug, ValueObject)]
+    #[value_object(
+        load_fn="Coordinates::new",
+        error_type="String",
+        serde_as="CoordinatesProxy",
+        from_str_derive=false,
+        display_derive=false,
+        eq_derive=false,
+        ord_derive=false,
+        hash_derive=false,
+        inner_attrs(serde(deserialize_with = "clamp_latitude"))
+    )]
+    pub struct Coordinates((f64, f64));
+
+    impl Coordinates {
+        fn new(value: (f64, f64)) -> Result<Coordinates, String> {
+            return Ok(Coordinates(value));
+        }
+    }
+
+    let value = Coordinates::new((1.5, 2.5)).unwrap();
+    let json = serde_json::to_string(&value).unwrap();
+    assert_eq!(json, r#"{"lat":1.5,"lon":2.5}"#);
+
+    let parsed: Coordinates = serde_json::from_str(&json).unwrap();
+    assert_eq!(parsed.into_inner(), (1.5, 2.5));
+
+    let clamped: Coordinates = serde_json::from_str(r#"{"lat":200.0,"lon":2.5}"#).unwrap();
+    assert_eq!(clamped.into_inner(), (90.0, 2.5));
+}
+
+#[test]
+fn test_inner_attrs_rename_wraps_the_proxy_under_the_given_key() {
+    #[derive(Debug, ValueObject)]
+    #[value_object(
+        load_fn="Coordinates::new",
+        error_type="String",
+        serde_as="CoordinatesProxy",
+        from_str_derive=false,
+        display_derive=false,
+        eq_derive=false,
+        ord_derive=false,
+        hash_derive=false,
+        inner_attrs(serde(rename="coordinates"))
+    )]
+    pub struct Coordinates((f64, f64));
+
+    impl Coordinates {
+        fn new(value: (f64, f64)) -> Result<Coordinates, String> {
+            return Ok(Coordinates(value));
+        }
+    }
+
+    let value = Coordinates::new((1.5, 2.5)).unwrap();
+    let json = serde_json::to_string(&value).unwrap();
+    assert_eq!(json, r#"{"coordinates":{"lat":1.5,"lon":2.5}}"#);
+
+    let parsed: Coordinates = serde_json::from_str(&json).unwrap();
+    assert_eq!(parsed.into_inner(), (1.5, 2.5));
+}
@@ -0,0 +1,34 @@
+#[macro_use]
+extern crate derive_value_object;
+
+use std::num::ParseIntError;
+use std::str::FromStr;
+
+fn map_parse_error(_error: ParseIntError) -> String {
+    return "not a valid port number".to_string();
+}
+
+#[test]
+fn test_parse_error_fn_maps_the_inner_type_parse_error() {
+    #[derive(Debug, ValueObject)]
+    #[value_object(load_fn="Port::new", error_type="String", parse_error_fn="map_parse_error")]
+    pub struct Port(u16);
+
+    impl Port {
+        fn new(value: u16) -> Result<Port, String> {
+            if value == 0 {
+                return Err("port must not be zero".to_string());
+            }
+            return Ok(Port(value));
+        }
+    }
+
+    let port = Port::from_str("8080").unwrap();
+    assert_eq!(port.into_inner(), 8080);
+
+    let error = Port::from_str("not-a-number").unwrap_err();
+    assert_eq!(error, "not a valid port number");
+
+    let error = Port::from_str("0").unwrap_err();
+    assert_eq!(error, "port must not be zero");
+}
@@ -0,0 +1,92 @@
+#[macro_use]
+extern crate derive_value_object;
+
+use serde::{Serialize, Deserialize};
+use std::convert::TryFrom;
+
+#[derive(Debug, Clone, Serialize, Deserialize)]
+struct CelsiusProxy(f64);
+
+impl From<f64> for CelsiusProxy {
+    fn from(value: f64) -> CelsiusProxy {
+        return CelsiusProxy(value);
+    }
+}
+
+impl TryFrom<CelsiusProxy> for f64 {
+    type Error = String;
+    fn try_from(value: CelsiusProxy) -> Result<f64, String> {
+        if value.0 < -273.15 {
+            return Err("below absolute zero".to_string());
+        }
+        return Ok(value.0);
+    }
+}
+
+#[test]
+fn test_serde_as_try_from_roundtrips_and_rejects_invalid_proxy_values() {
+    #[derive(Debug, ValueObject)]
+    #[value_object(
+        load_fn="Celsius::new",
+        error_type="String",
+        serde_as="CelsiusProxy",
+        serde_as_try_from=true,
+        from_str_derive=false,
+        eq_derive=false,
+        ord_derive=false,
+        hash_derive=false
+    )]
+    pub struct Celsius(f64);
+
+    impl Celsius {
+        fn new(value: f64) -> Result<Celsius, String> {
+            return Ok(Celsius(value));
+        }
+    }
+
+    let value = Celsius::new(20.0).unwrap();
+    let json = serde_json::to_string(&value).unwrap();
+    let parsed: Celsius = serde_json::from_str(&json).unwrap();
+    assert_eq!(parsed.into_inner(), 20.0);
+
+    let result: Result<Celsius, _> = serde_json::from_str("-300.0");
+    assert!(result.is_err());
+}
+
+#[derive(Debug, Clone, Serialize, Deserialize)]
+struct NameProxy(String);
+
+impl From<String> for NameProxy {
+    fn from(value: String) -> NameProxy {
+        return NameProxy(value);
+    }
+}
+
+impl From<NameProxy> for String {
+    fn from(value: NameProxy) -> String {
+        return value.0;
+    }
+}
+
+#[test]
+fn test_serde_as_into_roundtrips() {
+    #[derive(Debug, ValueObject)]
+    #[value_object(
+        load_fn="Name::new",
+        error_type="String",
+        serde_as="NameProxy",
+        from_str_derive=false
+    )]
+    pub struct Name(String);
+
+    impl Name {
+        fn new(value: String) -> Result<Name, String> {
+            return Ok(Name(value));
+        }
+    }
+
+    let value = Name::new("Ada".to_string()).unwrap();
+    let json = serde_json::to_string(&value).unwrap();
+    let parsed: Name = serde_json::from_str(&json).unwrap();
+    assert_eq!(parsed.into_inner(), "Ada");
+}